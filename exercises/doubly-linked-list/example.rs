@@ -1,31 +1,43 @@
 // this module adds some functionality based on the required implementations here
 // like: `LinkedList::pop_back`, `Clone` and `IntoIterator` for `LinkedList<T>`
 // You are free to use anything in it, but it's primarily for the test framework.
+#![no_std]
+#![feature(allocator_api)]
+
+extern crate alloc;
+
 mod pre_implemented;
 
-use std::ptr::NonNull;
+use alloc::alloc::{Allocator, Global};
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr::NonNull;
 
 type NodePtr<T> = NonNull<Node<T>>;
 type OptNodePtr<T> = Option<NodePtr<T>>;
 
-pub struct LinkedList<T> {
+pub struct LinkedList<T, A: Allocator = Global> {
     back: OptNodePtr<T>,
     front: OptNodePtr<T>,
     len: usize,
+    // the allocator every `Node` is placed in and freed from; `Global` unless
+    // the list was built with `new_in`
+    alloc: A,
     // The PhantomData signals dropck that we actually own `T`
     // I'm only aware of one case where this actually matters, which is when
     // using the dropck_eyepatch feature in Drop. We aren't using that here, so this is likely
     // unnecessary.
     // It can't hurt however and I'm not entirely certain that dropck_eyepatch is the only
     // case where it matters
-    marker: std::marker::PhantomData<Box<T>>,
+    marker: PhantomData<Box<T>>,
 }
 
-unsafe impl<T: Send> Send for LinkedList<T> {}
-unsafe impl<T: Sync> Sync for LinkedList<T> {}
+unsafe impl<T: Send, A: Allocator + Send> Send for LinkedList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for LinkedList<T, A> {}
 
-pub struct Cursor<'a, T> {
-    list: &'a mut LinkedList<T>,
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    list: &'a mut LinkedList<T, A>,
     node: OptNodePtr<T>,
 }
 
@@ -36,18 +48,20 @@ struct Node<T> {
 }
 
 impl<T> Node<T> {
-    fn new_linkless(element: T) -> NodePtr<T> {
-        Self::allocate(element, None, None)
+    fn new_linkless<A: Allocator>(element: T, alloc: &A) -> NodePtr<T> {
+        Self::allocate(element, None, None, alloc)
     }
 
-    fn allocate(element: T, prev: OptNodePtr<T>, next: OptNodePtr<T>) -> NodePtr<T> {
-        unsafe {
-            NonNull::new_unchecked(Box::into_raw(Box::new(Self {
-                element,
-                next,
-                prev,
-            })))
-        }
+    fn allocate<A: Allocator>(
+        element: T,
+        prev: OptNodePtr<T>,
+        next: OptNodePtr<T>,
+        alloc: &A,
+    ) -> NodePtr<T> {
+        let boxed = Box::new_in(Self { element, next, prev }, alloc);
+        // drop the `&A` half: it's just a borrow of the list's allocator
+        let (ptr, _) = Box::into_raw_with_allocator(boxed);
+        unsafe { NonNull::new_unchecked(ptr) }
     }
 }
 
@@ -56,11 +70,11 @@ trait NodePtrHelper<T> {
     fn get_prev(&mut self) -> &mut OptNodePtr<T>;
     fn link(left: NodePtr<T>, right: NodePtr<T>);
     fn insert_between(self, prev: NodePtr<T>, next: NodePtr<T>) -> NodePtr<T>;
-    fn insert_new_after(self, element: T) -> NodePtr<T>;
-    fn insert_new_before(self, element: T) -> NodePtr<T>;
+    fn insert_new_after<A: Allocator>(self, element: T, alloc: &A) -> NodePtr<T>;
+    fn insert_new_before<A: Allocator>(self, element: T, alloc: &A) -> NodePtr<T>;
     fn unlink_next(&mut self) -> OptNodePtr<T>;
     fn unlink_prev(&mut self) -> OptNodePtr<T>;
-    fn into_inner(self) -> T;
+    fn into_inner<A: Allocator>(self, alloc: &A) -> T;
 }
 
 impl<T> NodePtrHelper<T> for NodePtr<T> {
@@ -83,23 +97,23 @@ impl<T> NodePtrHelper<T> for NodePtr<T> {
         self
     }
 
-    fn insert_new_after(mut self, element: T) -> Self {
+    fn insert_new_after<A: Allocator>(mut self, element: T, alloc: &A) -> Self {
         if let Some(next) = *self.get_next() {
-            Node::new_linkless(element)
+            Node::new_linkless(element, alloc)
                 .insert_between(self, next)
         } else {
-            let new_node = Node::new_linkless(element);
+            let new_node = Node::new_linkless(element, alloc);
             NodePtr::link(self, new_node);
             new_node
         }
     }
 
-    fn insert_new_before(mut self, element: T) -> Self {
+    fn insert_new_before<A: Allocator>(mut self, element: T, alloc: &A) -> Self {
         if let Some(prev) = *self.get_prev() {
-            Node::new_linkless(element)
+            Node::new_linkless(element, alloc)
                 .insert_between(prev, self)
         } else {
-            let new_node = Node::new_linkless(element);
+            let new_node = Node::new_linkless(element, alloc);
             NodePtr::link(new_node, self);
             new_node
         }
@@ -124,20 +138,373 @@ impl<T> NodePtrHelper<T> for NodePtr<T> {
 
     // must not be linked to from other pointers
     // own links are irrelevant
-    fn into_inner(self) -> T {
+    fn into_inner<A: Allocator>(self, alloc: &A) -> T {
+        unsafe {
+            Box::from_raw_in(self.as_ptr(), alloc).element
+        }
+    }
+}
+
+/// The `next`/`prev` pointers an intrusive node embeds in itself.
+///
+/// Unlike [`Node`], which this module allocates and owns, a `Links<T>` lives
+/// inside a value the *caller* owns. The list only reads and writes these two
+/// fields to thread the value into place; it never moves or drops the value.
+/// Place one of these in your type and hand the list out via a [`Link`]
+/// adapter.
+pub struct Links<T> {
+    next: Option<NonNull<T>>,
+    prev: Option<NonNull<T>>,
+}
+
+impl<T> Links<T> {
+    /// An unlinked pair of pointers, suitable for a node that is not yet in a
+    /// list.
+    pub const fn new() -> Self {
+        Links {
+            next: None,
+            prev: None,
+        }
+    }
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Links::new()
+    }
+}
+
+/// Maps a handle type onto the intrusive [`Links`] embedded in its target.
+///
+/// An adapter implementing `Link` is how an [`IntrusiveList`] learns three
+/// things: how to get a raw pointer out of an owned handle (`as_ptr`), where
+/// the `Links` field lives inside the pointed-to value (`links`), and how to
+/// reconstitute the owned handle when a node leaves the list (`from_ptr`).
+/// Because `links` is chosen per adapter, the same target type can expose
+/// several independent `Links` fields through several adapters — see the
+/// multi-list note on [`IntrusiveCursor`].
+///
+/// # Safety
+///
+/// Implementors must ensure that `as_ptr` and `from_ptr` round-trip the same
+/// allocation, that `links` always points at a valid `Links<Target>` that
+/// lives as long as the target, and that a given `Links` field is only ever
+/// threaded into one list at a time.
+pub unsafe trait Link {
+    /// The owned handle the caller gives up on insertion and gets back on
+    /// removal (e.g. `Box<T>`, `Pin<Box<T>>`, `Arc<T>`).
+    type Handle;
+    /// The value the handle points at, which embeds the [`Links`].
+    type Target;
+
+    /// Borrow the raw pointer backing `handle` without consuming it.
+    fn as_ptr(handle: &Self::Handle) -> NonNull<Self::Target>;
+
+    /// Project to the [`Links`] field this adapter threads.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point at a live `Target`.
+    unsafe fn links(ptr: NonNull<Self::Target>) -> NonNull<Links<Self::Target>>;
+
+    /// Rebuild the owned handle from a pointer previously surrendered via
+    /// [`as_ptr`](Link::as_ptr).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have originated from `as_ptr` on a handle that has not since
+    /// been reconstituted.
+    unsafe fn from_ptr(ptr: NonNull<Self::Target>) -> Self::Handle;
+}
+
+/// An intrusive doubly-linked list.
+///
+/// Where [`LinkedList<T>`] allocates a [`Node<T>`] per element and owns it,
+/// `IntrusiveList` stores nothing of its own: each element carries its own
+/// [`Links`] and the list merely threads those together through the [`Link`]
+/// adapter `L`. Inserting hands the list a `L::Handle`; the list keeps only
+/// the raw pointer and leaks the handle's ownership back to the caller's
+/// allocation, so on drop it unthreads nothing and runs no destructors — the
+/// caller owns the nodes. This is what lets the same object be enqueued with
+/// no second allocation.
+pub struct IntrusiveList<L: Link> {
+    front: Option<NonNull<L::Target>>,
+    back: Option<NonNull<L::Target>>,
+    len: usize,
+    marker: PhantomData<L>,
+}
+
+impl<L: Link> IntrusiveList<L> {
+    pub fn new() -> Self {
+        IntrusiveList {
+            front: None,
+            back: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Thread `handle`'s node in at the front, surrendering its ownership to
+    /// the caller's allocation (the list only keeps the raw pointer).
+    pub fn push_front(&mut self, handle: L::Handle) {
+        let ptr = L::as_ptr(&handle);
+        mem::forget(handle);
+        unsafe {
+            let mut links = L::links(ptr);
+            links.as_mut().next = self.front;
+            links.as_mut().prev = None;
+            match self.front {
+                Some(old) => L::links(old).as_mut().prev = Some(ptr),
+                None => self.back = Some(ptr),
+            }
+        }
+        self.front = Some(ptr);
+        self.len += 1;
+    }
+
+    /// Thread `handle`'s node in at the back. See [`push_front`](Self::push_front).
+    pub fn push_back(&mut self, handle: L::Handle) {
+        let ptr = L::as_ptr(&handle);
+        mem::forget(handle);
         unsafe {
-            Box::from_raw(self.as_ptr()).element
+            let mut links = L::links(ptr);
+            links.as_mut().prev = self.back;
+            links.as_mut().next = None;
+            match self.back {
+                Some(old) => L::links(old).as_mut().next = Some(ptr),
+                None => self.front = Some(ptr),
+            }
         }
+        self.back = Some(ptr);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<L::Handle> {
+        let ptr = self.front?;
+        unsafe { Some(self.unlink(ptr)) }
+    }
+
+    pub fn pop_back(&mut self) -> Option<L::Handle> {
+        let ptr = self.back?;
+        unsafe { Some(self.unlink(ptr)) }
+    }
+
+    // Splice `ptr` out of the list, clear its links and hand back the owned
+    // handle. `ptr` must currently be threaded into this list.
+    unsafe fn unlink(&mut self, ptr: NonNull<L::Target>) -> L::Handle {
+        let (prev, next) = {
+            let links = L::links(ptr).as_ref();
+            (links.prev, links.next)
+        };
+        match prev {
+            Some(p) => L::links(p).as_mut().next = next,
+            None => self.front = next,
+        }
+        match next {
+            Some(n) => L::links(n).as_mut().prev = prev,
+            None => self.back = prev,
+        }
+        let mut links = L::links(ptr);
+        links.as_mut().next = None;
+        links.as_mut().prev = None;
+        self.len -= 1;
+        L::from_ptr(ptr)
+    }
+
+    pub fn cursor_front(&mut self) -> IntrusiveCursor<L> {
+        IntrusiveCursor {
+            node: self.front,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&mut self) -> IntrusiveCursor<L> {
+        IntrusiveCursor {
+            node: self.back,
+            list: self,
+        }
+    }
+}
+
+impl<L: Link> Default for IntrusiveList<L> {
+    fn default() -> Self {
+        IntrusiveList::new()
     }
 }
 
+// The list owns no elements, so `Drop` is a no-op beyond dropping the struct
+// itself: unthreading the caller-owned nodes is not our job.
+impl<L: Link> Drop for IntrusiveList<L> {
+    fn drop(&mut self) {}
+}
+
+/// A position inside an [`IntrusiveList`], reading the embedded [`Links`] to
+/// step between nodes.
+///
+/// Every operation here goes through the adapter's own [`Link::links`]
+/// projection and no other, so a target that embeds several `Links` fields
+/// (say `links_ready: Links<Self>` and `links_all: Links<Self>`) can be
+/// threaded into two lists at once through two adapters, and a cursor over one
+/// list never disturbs the other's pointers.
+pub struct IntrusiveCursor<'a, L: Link> {
+    list: &'a mut IntrusiveList<L>,
+    node: Option<NonNull<L::Target>>,
+}
+
+impl<L: Link> IntrusiveCursor<'_, L> {
+    /// The target under the cursor, if any.
+    pub fn peek(&self) -> Option<NonNull<L::Target>> {
+        self.node
+    }
+
+    pub fn next(&mut self) -> Option<NonNull<L::Target>> {
+        let node = self.node?;
+        let next = unsafe { L::links(node).as_ref().next };
+        self.node = next;
+        next
+    }
+
+    pub fn prev(&mut self) -> Option<NonNull<L::Target>> {
+        let node = self.node?;
+        let prev = unsafe { L::links(node).as_ref().prev };
+        self.node = prev;
+        prev
+    }
+
+    /// Unthread the node under the cursor from *this* list only, leaving any
+    /// other list it belongs to untouched, and hand back its owned handle. The
+    /// cursor advances to the following node.
+    pub fn take(&mut self) -> Option<L::Handle> {
+        let node = self.node?;
+        let next = unsafe { L::links(node).as_ref().next };
+        self.node = next;
+        Some(unsafe { self.list.unlink(node) })
+    }
+
+    /// Thread `handle`'s node in immediately after the cursor, surrendering
+    /// its ownership to the caller's allocation. With an empty list this seeds
+    /// it and parks the cursor on the new node.
+    pub fn insert_after(&mut self, handle: L::Handle) {
+        let new = L::as_ptr(&handle);
+        mem::forget(handle);
+        match self.node {
+            None => {
+                unsafe {
+                    let mut links = L::links(new);
+                    links.as_mut().next = None;
+                    links.as_mut().prev = None;
+                }
+                self.list.front = Some(new);
+                self.list.back = Some(new);
+                self.list.len += 1;
+                self.node = Some(new);
+            }
+            Some(node) => unsafe { self.splice_in(node, new, false) },
+        }
+    }
+
+    /// Thread `handle`'s node in immediately before the cursor. See
+    /// [`insert_after`](Self::insert_after).
+    pub fn insert_before(&mut self, handle: L::Handle) {
+        let new = L::as_ptr(&handle);
+        mem::forget(handle);
+        match self.node {
+            None => {
+                unsafe {
+                    let mut links = L::links(new);
+                    links.as_mut().next = None;
+                    links.as_mut().prev = None;
+                }
+                self.list.front = Some(new);
+                self.list.back = Some(new);
+                self.list.len += 1;
+                self.node = Some(new);
+            }
+            Some(node) => unsafe { self.splice_in(node, new, true) },
+        }
+    }
+
+    // Link `new` adjacent to `node`: before it when `before`, else after it.
+    // Touches only the adapter's own `Links` field.
+    unsafe fn splice_in(&mut self, node: NonNull<L::Target>, new: NonNull<L::Target>, before: bool) {
+        let (prev, next) = if before {
+            (L::links(node).as_ref().prev, Some(node))
+        } else {
+            (Some(node), L::links(node).as_ref().next)
+        };
+        {
+            let mut links = L::links(new);
+            links.as_mut().prev = prev;
+            links.as_mut().next = next;
+        }
+        match prev {
+            Some(p) => L::links(p).as_mut().next = Some(new),
+            None => self.list.front = Some(new),
+        }
+        match next {
+            Some(n) => L::links(n).as_mut().prev = Some(new),
+            None => self.list.back = Some(new),
+        }
+        self.list.len += 1;
+    }
+}
+
+// Take a list apart into its `(front, back, len)` without running its `Drop`,
+// which would free the very nodes we're about to re-home. Returns `None` for
+// an empty list.
+fn disassemble<T, A: Allocator>(list: LinkedList<T, A>) -> Option<(NodePtr<T>, NodePtr<T>, usize)> {
+    let list = mem::ManuallyDrop::new(list);
+    Some((list.front?, list.back?, list.len))
+}
+
+// Build a fresh list, in `alloc`, that owns the chain running from `front` to
+// `back`.
+fn assemble<T, A: Allocator>(
+    front: NodePtr<T>,
+    back: NodePtr<T>,
+    len: usize,
+    alloc: A,
+) -> LinkedList<T, A> {
+    let mut list = LinkedList::new_in(alloc);
+    list.front = Some(front);
+    list.back = Some(back);
+    list.len = len;
+    list
+}
+
+// Count the nodes reachable forward from `node`, inclusive.
+fn count_from<T>(mut node: OptNodePtr<T>) -> usize {
+    let mut len = 0;
+    while let Some(mut cur) = node {
+        len += 1;
+        node = *cur.get_next();
+    }
+    len
+}
+
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
+        LinkedList::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Build an empty list whose nodes are placed in, and freed from, `alloc`.
+    pub fn new_in(alloc: A) -> Self {
         LinkedList {
             back: None,
             front: None,
             len: 0,
-            marker: std::marker::PhantomData,
+            alloc,
+            marker: PhantomData,
         }
     }
 
@@ -145,14 +512,14 @@ impl<T> LinkedList<T> {
         self.len
     }
 
-    pub fn cursor_front(&mut self) -> Cursor<T> {
+    pub fn cursor_front(&mut self) -> Cursor<T, A> {
         Cursor {
             node: self.front,
             list: self,
         }
     }
 
-    pub fn cursor_back(&mut self) -> Cursor<T> {
+    pub fn cursor_back(&mut self) -> Cursor<T, A> {
         Cursor {
             node: self.back,
             list: self,
@@ -161,38 +528,194 @@ impl<T> LinkedList<T> {
 
     pub fn iter(&self) -> Iter<T> {
         Iter {
-            next_node: self.front,
-            marker: std::marker::PhantomData,
+            head: self.front,
+            tail: self.back,
+            len: self.len,
+            marker: PhantomData,
         }
     }
+
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            head: self.front,
+            tail: self.back,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Consume the list from the front, yielding owned elements. Whatever is
+    /// left when the `Drain` is dropped is unlinked and dropped too.
+    pub fn drain(&mut self) -> Drain<T, A> {
+        Drain { list: self }
+    }
+
+    /// Unlink the exact node named by `handle` in O(1) and return its element.
+    ///
+    /// The node's `prev`/`next` are spliced together and `front`/`back`/`len`
+    /// patched as needed. Consuming `handle` by value is what makes a second
+    /// `remove` of the same node impossible.
+    pub fn remove(&mut self, handle: NodeHandle<T>) -> T {
+        let mut node = handle.node;
+        let prev = *node.get_prev();
+        let next = *node.get_next();
+        match prev {
+            Some(mut prev) => *prev.get_next() = next,
+            None => self.front = next,
+        }
+        match next {
+            Some(mut next) => *next.get_prev() = prev,
+            None => self.back = prev,
+        }
+        self.len -= 1;
+        node.into_inner(&self.alloc)
+    }
 }
 
-impl<T> Drop for LinkedList<T> {
+impl<T, A: Allocator> Drop for LinkedList<T, A> {
     fn drop(&mut self) {
         let mut cursor = self.cursor_front();
-        while let Some(_) = cursor.take() {}
+        while cursor.take().is_some() {}
     }
 }
 
+// A pair of cursors walking inward from each end; `len` is how many nodes
+// still lie from `head` to `tail` (inclusive of both), so the forward and
+// backward ends stop the moment they would meet and never alias.
 pub struct Iter<'a, T> {
-    next_node: OptNodePtr<T>,
-    marker: std::marker::PhantomData<&'a LinkedList<T>>,
+    head: OptNodePtr<T>,
+    tail: OptNodePtr<T>,
+    len: usize,
+    marker: PhantomData<&'a T>,
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let node_ptr = self.next_node?;
+        if self.len == 0 {
+            return None;
+        }
+        let node_ptr = self.head?;
+        unsafe {
+            let node = &*node_ptr.as_ptr();
+            self.head = node.next;
+            self.len -= 1;
+            Some(&node.element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node_ptr = self.tail?;
+        unsafe {
+            let node = &*node_ptr.as_ptr();
+            self.tail = node.prev;
+            self.len -= 1;
+            Some(&node.element)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// The mutable counterpart of [`Iter`], yielding `&mut T`. The same inward
+/// two-cursor invariant keeps the front and back ends from ever handing out
+/// aliasing references to the same node.
+pub struct IterMut<'a, T> {
+    head: OptNodePtr<T>,
+    tail: OptNodePtr<T>,
+    len: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node_ptr = self.head?;
         unsafe {
-            let current_node = &*node_ptr.as_ptr();
-            self.next_node = current_node.next;
-            Some(&current_node.element)
+            let node = &mut *node_ptr.as_ptr();
+            self.head = node.next;
+            self.len -= 1;
+            Some(&mut node.element)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
 }
 
-impl<T> Cursor<'_, T> {
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node_ptr = self.tail?;
+        unsafe {
+            let node = &mut *node_ptr.as_ptr();
+            self.tail = node.prev;
+            self.len -= 1;
+            Some(&mut node.element)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Owning drain from the front of a [`LinkedList`]. Elements not taken before
+/// the `Drain` is dropped are still unlinked and dropped, so the list is left
+/// empty either way.
+pub struct Drain<'a, T, A: Allocator = Global> {
+    list: &'a mut LinkedList<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mut front = self.list.front?;
+        let next = *front.get_next();
+        match next {
+            Some(mut next) => {
+                *next.get_prev() = None;
+                self.list.front = Some(next);
+            }
+            None => {
+                self.list.front = None;
+                self.list.back = None;
+            }
+        }
+        self.list.len -= 1;
+        Some(front.into_inner(&self.list.alloc))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<T, A: Allocator> Cursor<'_, T, A> {
     pub fn peek_mut(&mut self) -> Option<&mut T> {
         unsafe {
             self.node.map(|node| &mut (*node.as_ptr()).element)
@@ -246,40 +769,404 @@ impl<T> Cursor<'_, T> {
             },
         };
         self.list.len -= 1;
-        Some(node.into_inner())
+        Some(node.into_inner(&self.list.alloc))
     }
 
-    pub fn insert_after(&mut self, element: T) {
+    /// Graft every node of `other` in immediately after the cursor in O(1),
+    /// leaving `other` empty. The four boundary pointers are relinked and the
+    /// two `len`s summed; no per-element work happens.
+    pub fn splice_after(&mut self, other: LinkedList<T, A>) {
+        let (o_front, o_back, o_len) = match disassemble(other) {
+            Some(parts) => parts,
+            None => return,
+        };
+        match self.node {
+            None => {
+                self.list.front = Some(o_front);
+                self.list.back = Some(o_back);
+                self.list.len = o_len;
+                self.node = Some(o_front);
+            }
+            Some(mut node) => {
+                let next = *node.get_next();
+                NodePtr::link(node, o_front);
+                match next {
+                    Some(next) => NodePtr::link(o_back, next),
+                    None => self.list.back = Some(o_back),
+                }
+                self.list.len += o_len;
+            }
+        }
+    }
+
+    /// Graft every node of `other` in immediately before the cursor. See
+    /// [`splice_after`](Self::splice_after).
+    pub fn splice_before(&mut self, other: LinkedList<T, A>) {
+        let (o_front, o_back, o_len) = match disassemble(other) {
+            Some(parts) => parts,
+            None => return,
+        };
+        match self.node {
+            None => {
+                self.list.front = Some(o_front);
+                self.list.back = Some(o_back);
+                self.list.len = o_len;
+                self.node = Some(o_back);
+            }
+            Some(mut node) => {
+                let prev = *node.get_prev();
+                NodePtr::link(o_back, node);
+                match prev {
+                    Some(prev) => NodePtr::link(prev, o_front),
+                    None => self.list.front = Some(o_front),
+                }
+                self.list.len += o_len;
+            }
+        }
+    }
+
+    /// Cut the list just after the cursor and return everything past it as a
+    /// new list, leaving the cursor node as the new `back`. The split-off part
+    /// is counted to fix up the two `len`s.
+    pub fn split_after(&mut self) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let mut node = match self.node {
+            Some(node) => node,
+            None => return LinkedList::new_in(self.list.alloc.clone()),
+        };
+        let tail_front = match node.unlink_next() {
+            Some(tail_front) => tail_front,
+            None => return LinkedList::new_in(self.list.alloc.clone()),
+        };
+        *node.get_next() = None;
+        let tail_back = self.list.back;
+        let tail_len = count_from(Some(tail_front));
+        self.list.back = Some(node);
+        self.list.len -= tail_len;
+        assemble(tail_front, tail_back.unwrap(), tail_len, self.list.alloc.clone())
+    }
+
+    /// Cut the list just before the cursor and return everything ahead of it
+    /// as a new list, leaving the cursor node as the new `front`. See
+    /// [`split_after`](Self::split_after).
+    pub fn split_before(&mut self) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let mut node = match self.node {
+            Some(node) => node,
+            None => return LinkedList::new_in(self.list.alloc.clone()),
+        };
+        let head_back = match node.unlink_prev() {
+            Some(head_back) => head_back,
+            None => return LinkedList::new_in(self.list.alloc.clone()),
+        };
+        *node.get_prev() = None;
+        let head_front = self.list.front;
+        let head_len = count_from(head_front);
+        self.list.front = Some(node);
+        self.list.len -= head_len;
+        assemble(head_front.unwrap(), head_back, head_len, self.list.alloc.clone())
+    }
+
+    pub fn insert_after(&mut self, element: T) -> NodeHandle<T> {
         self._insert(element, |list, cursor_node, element| {
-            let new_node = cursor_node.insert_new_after(element);
+            let new_node = cursor_node.insert_new_after(element, &list.alloc);
             if list.back == Some(cursor_node) {
                 list.back = Some(new_node);
             }
-        });
+            new_node
+        })
     }
 
-    pub fn insert_before(&mut self, element: T) {
+    pub fn insert_before(&mut self, element: T) -> NodeHandle<T> {
         self._insert(element, |list, cursor_node, element| {
-            let new_node = cursor_node.insert_new_before(element);
+            let new_node = cursor_node.insert_new_before(element, &list.alloc);
             if list.front == Some(cursor_node) {
                 list.front = Some(new_node);
             }
-        });
+            new_node
+        })
     }
 
-    // put into list, if empty, else do whatever callback says
-    fn _insert(&mut self, element: T, callback: impl Fn(&mut LinkedList<T>, NodePtr<T>, T)) {
+    // put into list, if empty, else do whatever callback says; either way hand
+    // back a `NodeHandle` pointing at the node just inserted
+    fn _insert(
+        &mut self,
+        element: T,
+        callback: impl Fn(&mut LinkedList<T, A>, NodePtr<T>, T) -> NodePtr<T>,
+    ) -> NodeHandle<T> {
         let cursor_node = match self.node {
             Some(node) => node,
             None => { // list empty
-                self.node = Some(Node::new_linkless(element));
+                let new_node = Node::new_linkless(element, &self.list.alloc);
+                self.node = Some(new_node);
                 self.list.back = self.node;
                 self.list.front = self.node;
                 self.list.len += 1;
-                return
+                return NodeHandle::new(new_node)
+            }
+        };
+        let new_node = callback(self.list, cursor_node, element);
+        self.list.len += 1;
+        NodeHandle::new(new_node)
+    }
+}
+
+/// An opaque token naming a single node in a [`LinkedList`].
+///
+/// Returned by [`Cursor::insert_after`]/[`Cursor::insert_before`], it lets a
+/// caller that remembers where it put something delete it later in O(1) via
+/// [`LinkedList::remove`] without walking a cursor to find it. The handle is
+/// neither `Clone` nor `Copy` and [`remove`](LinkedList::remove) takes it by
+/// value, so a node can be named — and therefore freed — exactly once.
+pub struct NodeHandle<T> {
+    node: NodePtr<T>,
+    marker: PhantomData<Box<T>>,
+}
+
+impl<T> NodeHandle<T> {
+    fn new(node: NodePtr<T>) -> Self {
+        NodeHandle {
+            node,
+            marker: PhantomData,
+        }
+    }
+}
+
+type XorNodePtr<T> = NonNull<XorNode<T>>;
+type OptXorNodePtr<T> = Option<XorNodePtr<T>>;
+
+/// A doubly-linked list that stores a single pointer-sized field per node.
+///
+/// Instead of a separate `next` and `prev`, each node keeps `npx`: the bitwise
+/// XOR of its two neighbours' addresses, with a missing neighbour counted as
+/// `0`. That halves the per-node pointer overhead, which matters on
+/// memory-constrained targets — at the price that you can no longer follow a
+/// lone node pointer in isolation. Traversal therefore threads through a
+/// [`XorCursor`], which remembers the node it arrived from so it can recover
+/// the node it is heading to (`npx ^ came_from`).
+pub struct XorLinkedList<T> {
+    front: OptXorNodePtr<T>,
+    back: OptXorNodePtr<T>,
+    len: usize,
+    marker: PhantomData<Box<T>>,
+}
+
+unsafe impl<T: Send> Send for XorLinkedList<T> {}
+unsafe impl<T: Sync> Sync for XorLinkedList<T> {}
+
+struct XorNode<T> {
+    element: T,
+    npx: usize,
+}
+
+impl<T> XorNode<T> {
+    fn allocate(element: T, npx: usize) -> XorNodePtr<T> {
+        unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(Self { element, npx }))) }
+    }
+}
+
+// The address a node pointer hashes into an `npx`, with `None` mapping to `0`
+// so the ends of the list anchor cleanly.
+fn xor_addr<T>(node: OptXorNodePtr<T>) -> usize {
+    node.map_or(0, |node| node.as_ptr() as usize)
+}
+
+fn xor_ptr<T>(addr: usize) -> OptXorNodePtr<T> {
+    NonNull::new(addr as *mut XorNode<T>)
+}
+
+// Read/modify a node's packed neighbour field.
+fn npx_of<T>(mut node: XorNodePtr<T>) -> usize {
+    unsafe { node.as_mut().npx }
+}
+
+fn set_npx<T>(mut node: XorNodePtr<T>, npx: usize) {
+    unsafe { node.as_mut().npx = npx }
+}
+
+impl<T> XorLinkedList<T> {
+    pub fn new() -> Self {
+        XorLinkedList {
+            front: None,
+            back: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        // neighbours are `None` and the old front
+        let new = XorNode::allocate(element, xor_addr(self.front));
+        match self.front {
+            Some(front) => set_npx(front, npx_of(front) ^ xor_addr(Some(new))),
+            None => self.back = Some(new),
+        }
+        self.front = Some(new);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        let new = XorNode::allocate(element, xor_addr(self.back));
+        match self.back {
+            Some(back) => set_npx(back, npx_of(back) ^ xor_addr(Some(new))),
+            None => self.front = Some(new),
+        }
+        self.back = Some(new);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let front = self.front?;
+        // the outward operand at the front is 0, so `npx` is the next address
+        let next = xor_ptr::<T>(npx_of(front));
+        match next {
+            Some(next) => set_npx(next, npx_of(next) ^ xor_addr(Some(front))),
+            None => self.back = None,
+        }
+        self.front = next;
+        self.len -= 1;
+        Some(unsafe { Box::from_raw(front.as_ptr()).element })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let back = self.back?;
+        let prev = xor_ptr::<T>(npx_of(back));
+        match prev {
+            Some(prev) => set_npx(prev, npx_of(prev) ^ xor_addr(Some(back))),
+            None => self.front = None,
+        }
+        self.back = prev;
+        self.len -= 1;
+        Some(unsafe { Box::from_raw(back.as_ptr()).element })
+    }
+
+    pub fn cursor_front(&mut self) -> XorCursor<T> {
+        XorCursor {
+            node: self.front,
+            came_from: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&mut self) -> XorCursor<T> {
+        // behind the back node is its only neighbour
+        let came_from = self.back.map_or(0, |back| npx_of(back));
+        XorCursor {
+            node: self.back,
+            came_from,
+            list: self,
+        }
+    }
+}
+
+impl<T> Default for XorLinkedList<T> {
+    fn default() -> Self {
+        XorLinkedList::new()
+    }
+}
+
+impl<T> Drop for XorLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+// Fix up the two ends of `old`'s neighbour `node` so that `node`'s slot now
+// names `new` instead of `old` (or clears it, when `new` is `None`).
+fn xor_relink<T>(node: OptXorNodePtr<T>, old: OptXorNodePtr<T>, new: OptXorNodePtr<T>) {
+    if let Some(node) = node {
+        set_npx(node, npx_of(node) ^ xor_addr(old) ^ xor_addr(new));
+    }
+}
+
+/// A position inside a [`XorLinkedList`].
+///
+/// Because a node's `npx` only yields the *other* neighbour once you know the
+/// one you came from, the cursor carries `came_from`: the address of the node
+/// on its `prev` side. The node on the `next` side is `npx ^ came_from`, and
+/// stepping is symmetric.
+pub struct XorCursor<'a, T> {
+    list: &'a mut XorLinkedList<T>,
+    node: OptXorNodePtr<T>,
+    came_from: usize,
+}
+
+impl<T> XorCursor<'_, T> {
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.node.map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    pub fn next(&mut self) -> Option<&mut T> {
+        let node = self.node?;
+        let next = xor_ptr::<T>(npx_of(node) ^ self.came_from);
+        self.came_from = xor_addr(Some(node));
+        self.node = next;
+        self.peek_mut()
+    }
+
+    pub fn prev(&mut self) -> Option<&mut T> {
+        let prev = xor_ptr::<T>(self.came_from)?;
+        let before = npx_of(prev) ^ xor_addr(self.node);
+        self.node = Some(prev);
+        self.came_from = before;
+        self.peek_mut()
+    }
+
+    /// Insert `element` between the cursor node (`a`) and the node on its
+    /// `next` side (`b`), updating each neighbour's `npx` by XOR-ing out the
+    /// old edge and XOR-ing in the new node. On an empty list this seeds it.
+    pub fn insert_after(&mut self, element: T) {
+        let a = match self.node {
+            Some(a) => a,
+            None => {
+                let new = XorNode::allocate(element, 0);
+                self.list.front = Some(new);
+                self.list.back = Some(new);
+                self.list.len += 1;
+                self.node = Some(new);
+                return;
             }
         };
-        callback(&mut self.list, cursor_node, element);
+        let b = xor_ptr::<T>(npx_of(a) ^ self.came_from);
+        let new = XorNode::allocate(element, xor_addr(Some(a)) ^ xor_addr(b));
+        set_npx(a, npx_of(a) ^ xor_addr(b) ^ xor_addr(Some(new)));
+        match b {
+            Some(b) => set_npx(b, npx_of(b) ^ xor_addr(Some(a)) ^ xor_addr(Some(new))),
+            None => self.list.back = Some(new),
+        }
         self.list.len += 1;
     }
+
+    /// Unlink the cursor node and return its element, advancing the cursor to
+    /// the node on its `next` side.
+    pub fn take(&mut self) -> Option<T> {
+        let node = self.node?;
+        let prev = xor_ptr::<T>(self.came_from);
+        let next = xor_ptr::<T>(npx_of(node) ^ self.came_from);
+        xor_relink(prev, Some(node), next);
+        xor_relink(next, Some(node), prev);
+        match prev {
+            Some(_) => {}
+            None => self.list.front = next,
+        }
+        match next {
+            Some(_) => {}
+            None => self.list.back = prev,
+        }
+        self.node = next;
+        self.list.len -= 1;
+        Some(unsafe { Box::from_raw(node.as_ptr()).element })
+    }
 }